@@ -1,3 +1,24 @@
+#![no_std]
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use digest::{
+    generic_array::typenum::U16, FixedOutput, FixedOutputReset, HashMarker, Output,
+    OutputSizeUser, Reset, Update,
+};
+
+pub mod hmac;
+pub mod md5;
+
+pub use hmac::Hmac;
+pub use md5::Md5;
+
 /// Implementation of the [MD2](https://datatracker.ietf.org/doc/html/rfc1319) hash algorithm.
 #[derive(Clone, Copy)]
 pub struct MD2 {
@@ -12,11 +33,11 @@ pub struct MD2 {
     count: usize
 }
 
-impl std::fmt::Display for MD2 {
+impl core::fmt::Display for MD2 {
     /// Format the final digest as a hex string without affecting state.
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // Compute the final checksum on a copy since [`finalize`] consumes self
-        let result = (*self).finalize();
+        let result = self.finalize_fixed();
 
         // Format the final checksum
         for b in result {
@@ -26,9 +47,9 @@ impl std::fmt::Display for MD2 {
     }
 }
 
-impl std::fmt::Debug for MD2 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <MD2 as std::fmt::Display>::fmt(self, f)
+impl core::fmt::Debug for MD2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <MD2 as core::fmt::Display>::fmt(self, f)
     }
 }
 
@@ -135,22 +156,65 @@ impl MD2 {
         self.buffer[..remaining].copy_from_slice(&input[offset..]);
     }
 
-    /// Consume self and return the computed digest.
-    pub fn finalize(mut self) -> [u8; 16] {
+    /// Compute the final digest over a copy of the current state.
+    ///
+    /// This performs the padding and checksum-append steps on a clone of `self`, leaving the
+    /// caller's state untouched so the hasher remains reusable. [`Self::finalize`] is the
+    /// owning convenience wrapper around this for callers that are done with the hasher.
+    fn finalize_fixed(&self) -> [u8; 16] {
+        let mut ctx = *self;
+
         // Compute padding bytes required
-        let padding_len = (16 - self.count) as u8;
+        let padding_len = (16 - ctx.count) as u8;
 
         // Take advantage of internals to directly shove padding bytes into input buffer
-        self.buffer[self.count..].fill(padding_len);
+        ctx.buffer[ctx.count..].fill(padding_len);
         // Apply internal update over the exactly filled and padded buffer
-        self._update(&self.buffer.clone());
+        ctx._update(&ctx.buffer.clone());
 
         // Finally append the checksum bytes (note the clone required to not borrow from self twice)
         // Again using [`Self::_update`] since we already know we're feeding it a properly sized buffer
-        self._update(&self.checksum.clone());
+        ctx._update(&ctx.checksum.clone());
 
         // Final hash is last state
-        self.state
+        ctx.state
+    }
+
+    /// Consume self and return the computed digest.
+    pub fn finalize(self) -> [u8; 16] {
+        MD2::finalize_fixed(&self)
+    }
+}
+
+impl HashMarker for MD2 {}
+
+impl OutputSizeUser for MD2 {
+    type OutputSize = U16;
+}
+
+impl Update for MD2 {
+    fn update(&mut self, data: &[u8]) {
+        // Forward to the inherent incremental update; inherent methods take priority here.
+        self.update(data);
+    }
+}
+
+impl Reset for MD2 {
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl FixedOutput for MD2 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&MD2::finalize_fixed(&self));
+    }
+}
+
+impl FixedOutputReset for MD2 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(&MD2::finalize_fixed(self));
+        Reset::reset(self);
     }
 }
 
@@ -160,6 +224,21 @@ impl Default for MD2 {
     }
 }
 
+/// Allow an [`MD2`] to be used as a [`std::io::Write`] sink so callers can stream large inputs
+/// (files, sockets) straight into the digest with [`std::io::copy`] instead of reading and feeding
+/// bytes by hand.
+#[cfg(feature = "std")]
+impl std::io::Write for MD2 {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Substitutions used in the computation of MD2; these are effectively just random bytes for any
 /// meaningful purposes.
 const S: [u8; 256] = [
@@ -178,9 +257,93 @@ const S: [u8; 256] = [
     219, 153, 141, 51, 159, 17, 131, 20,
 ];
 
+/// A hash algorithm selectable at runtime by the [`Hasher`] facade.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Algorithm {
+    /// The [MD2](MD2) algorithm.
+    Md2,
+    /// The [MD5](Md5) algorithm.
+    Md5,
+}
+
+impl Algorithm {
+    /// Create a fresh [`Hasher`] for this algorithm.
+    pub fn hasher(self) -> Hasher {
+        Hasher::new(self)
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = UnknownAlgorithm;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "md2" | "MD2" => Ok(Algorithm::Md2),
+            "md5" | "MD5" => Ok(Algorithm::Md5),
+            _ => Err(UnknownAlgorithm),
+        }
+    }
+}
+
+/// Error returned when [`Algorithm::from_str`] is given an unrecognized algorithm name.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UnknownAlgorithm;
+
+impl core::fmt::Display for UnknownAlgorithm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("unknown hash algorithm")
+    }
+}
+
+/// Runtime-dispatched hasher that forwards streaming input to whichever concrete algorithm was
+/// selected, modelled after `haggis-rs`'s `checksum.rs`. Pick an [`Algorithm`] (e.g. via
+/// [`Algorithm::from_str`]) and feed it incrementally like the underlying hashers.
+#[derive(Clone, Copy)]
+pub enum Hasher {
+    /// Wraps an [`MD2`] instance.
+    Md2(MD2),
+    /// Wraps an [`Md5`] instance.
+    Md5(Md5),
+}
+
+impl Hasher {
+    /// Create a new hasher for the given [`Algorithm`].
+    pub fn new(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Md2 => Hasher::Md2(MD2::new()),
+            Algorithm::Md5 => Hasher::Md5(Md5::new()),
+        }
+    }
+
+    /// The [`Algorithm`] this hasher is computing.
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            Hasher::Md2(_) => Algorithm::Md2,
+            Hasher::Md5(_) => Algorithm::Md5,
+        }
+    }
+
+    /// Provide input to compute the digest over.
+    pub fn update(&mut self, input: &[u8]) {
+        match self {
+            Hasher::Md2(h) => h.update(input),
+            Hasher::Md5(h) => h.update(input),
+        }
+    }
+
+    /// Consume self and return the computed digest bytes.
+    pub fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Md2(h) => h.finalize().to_vec(),
+            Hasher::Md5(h) => h.finalize().to_vec(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::MD2;
+    use std::string::ToString;
 
     /// Helper function to simplify testing hashes of strings against their known good results.
     fn test_hash(input: &str, expectation: &str) {
@@ -204,4 +367,23 @@ mod test {
                   "d5976f79d83d3a0dc9806c3c66f3efd8"
         );
     }
+
+    /// Check that the runtime facade selects the right algorithm and matches the direct API.
+    #[test]
+    fn facade_dispatch() {
+        use crate::{Algorithm, Hasher, Md5};
+        use core::str::FromStr;
+
+        assert_eq!(Algorithm::from_str("md2"), Ok(Algorithm::Md2));
+        assert_eq!(Algorithm::from_str("md5"), Ok(Algorithm::Md5));
+        assert!(Algorithm::from_str("sha1").is_err());
+
+        let mut hasher = Hasher::new(Algorithm::Md2);
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize(), MD2::with_input(b"abc").finalize().to_vec());
+
+        let mut hasher = Algorithm::Md5.hasher();
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize(), Md5::with_input(b"abc").finalize().to_vec());
+    }
 }
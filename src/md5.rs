@@ -0,0 +1,228 @@
+//! Implementation of the [MD5](https://datatracker.ietf.org/doc/html/rfc1321) hash algorithm.
+//!
+//! The structure mirrors the streaming [`MD2`](crate::MD2) implementation: an internal buffer
+//! accumulates input until a full block is available, at which point [`Md5::_update`] folds it
+//! into the running state.
+
+/// Implementation of the [MD5](https://datatracker.ietf.org/doc/html/rfc1321) hash algorithm.
+#[derive(Clone, Copy)]
+pub struct Md5 {
+    /// Running state words (`A`, `B`, `C`, `D`); become the digest after finalization.
+    state: [u32; 4],
+    /// Buffer holding input that hasn't yet filled a complete 64 byte block.
+    buffer: [u8; 64],
+    /// Number of valid bytes in [`Self::buffer`].
+    count: usize,
+    /// Total number of input bytes fed so far, used to emit the trailing length field.
+    length: u64,
+}
+
+/// Per-round left-rotate amounts, grouped into the four 16-round passes.
+const SHIFT: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Per-round additive constants, `T[i] = floor(2^32 * abs(sin(i + 1)))`.
+const T: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+impl Md5 {
+    /// Creates a new [`Md5`] ready for use.
+    pub const fn new() -> Self {
+        Self {
+            // Standard little-endian initialization vector.
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            buffer: [0u8; 64],
+            count: 0,
+            length: 0,
+        }
+    }
+
+    /// Creates a new [`Md5`] with an initial input buffer processed.
+    pub fn with_input(input: &[u8]) -> Self {
+        let mut initial = Self::new();
+        initial.update(input);
+        initial
+    }
+
+    /// Internally fold a single complete 64 byte block into the running state.
+    /// NOTE: As with [`MD2`](crate::MD2), this is internal and expects a full block.
+    fn _update(&mut self, input: &[u8]) {
+        debug_assert_eq!(input.len(), 64, "Provided input slice must be /exactly/ 64 bytes in size");
+
+        // Decode the block into 16 little-endian 32 bit words.
+        let mut m = [0u32; 16];
+        for (i, word) in m.iter_mut().enumerate() {
+            *word = u32::from_le_bytes([
+                input[i * 4],
+                input[i * 4 + 1],
+                input[i * 4 + 2],
+                input[i * 4 + 3],
+            ]);
+        }
+
+        let [mut a, mut b, mut c, mut d] = self.state;
+
+        for i in 0..64 {
+            // Select the nonlinear function and message-word index for the current pass.
+            let (f, g) = match i {
+                0..=15 => ((b & c) | (!b & d), i),
+                16..=31 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                32..=47 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+
+            let f = f
+                .wrapping_add(a)
+                .wrapping_add(T[i])
+                .wrapping_add(m[g]);
+
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(f.rotate_left(SHIFT[i]));
+        }
+
+        // Accumulate the working words back into the state.
+        self.state[0] = self.state[0].wrapping_add(a);
+        self.state[1] = self.state[1].wrapping_add(b);
+        self.state[2] = self.state[2].wrapping_add(c);
+        self.state[3] = self.state[3].wrapping_add(d);
+    }
+
+    /// Provide input to compute the digest over.
+    pub fn update(&mut self, input: &[u8]) {
+        self.length = self.length.wrapping_add(input.len() as u64);
+
+        // Fill the leftover buffer as far as the input allows.
+        let available = input.len().min(64 - self.count);
+        self.buffer[self.count..self.count + available].copy_from_slice(&input[..available]);
+        self.count += available;
+
+        // Bail out early if we still don't have a complete block (mirrors [`MD2::update`]).
+        if self.count == 64 {
+            self._update(&self.buffer.clone());
+        } else {
+            return;
+        }
+
+        // Process any remaining whole blocks straight from the input.
+        let mut offset = available;
+        let mut remaining = input.len() - available;
+        while remaining >= 64 {
+            self._update(&input[offset..offset + 64]);
+            remaining -= 64;
+            offset += 64;
+        }
+
+        // Stash the tail back into the leftover buffer.
+        self.count = remaining;
+        self.buffer[..remaining].copy_from_slice(&input[offset..]);
+    }
+
+    /// Compute the final digest over a copy of the current state, leaving `self` reusable.
+    fn finalize_fixed(&self) -> [u8; 16] {
+        let mut ctx = *self;
+
+        // Record the message length in bits before padding mutates the buffer.
+        let bit_length = ctx.length.wrapping_mul(8);
+
+        // Append the mandatory `0x80` byte.
+        ctx.update(&[0x80]);
+
+        // Zero-pad until the buffer is 56 mod 64, leaving room for the 64 bit length.
+        while ctx.count != 56 {
+            ctx.update(&[0x00]);
+        }
+
+        // Append the little-endian bit length and flush the final block.
+        ctx.update(&bit_length.to_le_bytes());
+
+        // Serialize the state words as little-endian to produce the digest.
+        let mut out = [0u8; 16];
+        for (i, word) in ctx.state.iter().enumerate() {
+            out[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        out
+    }
+
+    /// Consume self and return the computed digest.
+    pub fn finalize(self) -> [u8; 16] {
+        self.finalize_fixed()
+    }
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Display for Md5 {
+    /// Format the final digest as a hex string without affecting state.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let result = self.finalize_fixed();
+        for b in result {
+            f.write_fmt(format_args!("{:02x}", b))?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Md5 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Md5 as core::fmt::Display>::fmt(self, f)
+    }
+}
+
+/// Allow an [`Md5`] to be used as a [`std::io::Write`] sink, matching [`MD2`](crate::MD2).
+#[cfg(feature = "std")]
+impl std::io::Write for Md5 {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Md5;
+    use std::string::ToString;
+
+    /// Helper mirroring the MD2 tests for checking string hashes against known-good results.
+    fn test_hash(input: &str, expectation: &str) {
+        let result = Md5::with_input(input.as_bytes()).to_string();
+        assert_eq!(result, expectation,
+            "Testing hash for \"{}\", expected \"{}\" but got \"{}\"", input, expectation, result);
+    }
+
+    /// Test against the reference hashes from the [RFC](https://datatracker.ietf.org/doc/html/rfc1321).
+    #[test]
+    fn basic() {
+        test_hash("", "d41d8cd98f00b204e9800998ecf8427e");
+        test_hash("a", "0cc175b9c0f1b6a831c399e269772661");
+        test_hash("abc", "900150983cd24fb0d6963f7d28e17f72");
+        test_hash("message digest", "f96b697d7cb7938d525a2f31aaf161d0");
+        test_hash("abcdefghijklmnopqrstuvwxyz", "c3fcd3d76192e4007dfb496cca67e13b");
+        test_hash("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                  "d174ab98d277d9f5a5611c2c9f419d9f"
+        );
+        test_hash("12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                  "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+}